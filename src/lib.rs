@@ -1,8 +1,8 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short,
-    Address, BytesN, Env, String,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token,
+    Address, Bytes, BytesN, Env, String, Vec,
 };
 
 #[contracterror]
@@ -13,6 +13,10 @@ pub enum Error {
     BadState = 3,
     EscrowNotFunded = 4,
     AlreadySettled = 5,
+    NotInitialized = 6,
+    AlreadyInitialized = 7,
+    Paused = 8,
+    InvalidMilestones = 9,
 }
 
 #[contracttype]
@@ -44,14 +48,26 @@ pub struct FreightContract {
     pub total_km: u32,
     pub computed_cost: i128,
     pub last_paid: i128,
+    pub telemetry_hash: BytesN<32>,
+    pub telemetry_seq: u64,
+    pub milestones: Vec<(u32, u16)>,
+    pub released_bps: u32,
+    pub released_count: u32,
+    pub released_amount: i128,
 }
 
 #[contracttype]
 enum DataKey {
     NextId,
     Contract(u128),
+    Archived(u128),
+    Admin,
+    Paused,
 }
 
+const ARCHIVE_TTL_THRESHOLD: u32 = 100;
+const ARCHIVE_TTL_EXTEND: u32 = 6_312_000;
+
 fn put<T: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(e: &Env, k: &DataKey, v: &T) {
     e.storage().instance().set(k, v);
     e.storage().instance().extend_ttl(50, 200);
@@ -60,6 +76,52 @@ fn get<T: soroban_sdk::TryFromVal<Env, soroban_sdk::Val>>(e: &Env, k: &DataKey)
     e.storage().instance().get(k)
 }
 
+fn archive_contract(e: &Env, id: u128, fc: &FreightContract) {
+    e.storage().instance().remove(&DataKey::Contract(id));
+    let key = DataKey::Archived(id);
+    e.storage().persistent().set(&key, fc);
+    e.storage().persistent().extend_ttl(&key, ARCHIVE_TTL_THRESHOLD, ARCHIVE_TTL_EXTEND);
+}
+
+fn get_contract_either(e: &Env, id: u128) -> Option<FreightContract> {
+    get(e, &DataKey::Contract(id)).or_else(|| e.storage().persistent().get(&DataKey::Archived(id)))
+}
+
+fn require_not_paused(e: &Env) -> Result<(), Error> {
+    let paused: bool = get(e, &DataKey::Paused).unwrap_or(false);
+    if paused { return Err(Error::Paused); }
+    Ok(())
+}
+
+fn validate_milestones(milestones: &Vec<(u32, u16)>) -> Result<(), Error> {
+    let mut last_km: u32 = 0;
+    let mut bps_sum: u32 = 0;
+    for (i, (threshold_km, bps)) in milestones.iter().enumerate() {
+        if i > 0 && threshold_km <= last_km { return Err(Error::InvalidMilestones); }
+        last_km = threshold_km;
+        bps_sum += bps as u32;
+    }
+    if bps_sum > 10_000 { return Err(Error::InvalidMilestones); }
+    Ok(())
+}
+
+fn chain_telemetry(
+    e: &Env,
+    prev_hash: &BytesN<32>,
+    seq: u64,
+    add_secs: u32,
+    add_km: u32,
+    add_cost: i128,
+) -> BytesN<32> {
+    let mut data = Bytes::new(e);
+    data.append(&Bytes::from_array(e, &prev_hash.to_array()));
+    data.append(&Bytes::from_array(e, &seq.to_be_bytes()));
+    data.append(&Bytes::from_array(e, &add_secs.to_be_bytes()));
+    data.append(&Bytes::from_array(e, &add_km.to_be_bytes()));
+    data.append(&Bytes::from_array(e, &add_cost.to_be_bytes()));
+    e.crypto().sha256(&data).into()
+}
+
 #[contract]
 pub struct RoadFreight;
 
@@ -72,6 +134,36 @@ impl RoadFreight {
         id
     }
 
+    pub fn initialize(e: Env, admin: Address) -> Result<(), Error> {
+        if get::<Address>(&e, &DataKey::Admin).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        admin.require_auth();
+        put(&e, &DataKey::Admin, &admin);
+        put(&e, &DataKey::Paused, &false);
+        Ok(())
+    }
+
+    pub fn pause(e: Env, admin: Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = get(&e, &DataKey::Admin).ok_or(Error::NotInitialized)?;
+        if stored_admin != admin { return Err(Error::Unauthorized); }
+
+        put(&e, &DataKey::Paused, &true);
+        e.events().publish((symbol_short!("EV"), symbol_short!("PAUSED")), ());
+        Ok(())
+    }
+
+    pub fn resume(e: Env, admin: Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = get(&e, &DataKey::Admin).ok_or(Error::NotInitialized)?;
+        if stored_admin != admin { return Err(Error::Unauthorized); }
+
+        put(&e, &DataKey::Paused, &false);
+        e.events().publish((symbol_short!("EV"), symbol_short!("RESUMED")), ());
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn create_contract(
         e: Env,
@@ -83,8 +175,10 @@ impl RoadFreight {
         price: i128,
         deadline_unix: u64,
         doc_hash: BytesN<32>,
+        milestones: Vec<(u32, u16)>,
     ) -> Result<u128, Error> {
         shipper.require_auth();
+        validate_milestones(&milestones)?;
 
         let id = Self::next_id(&e);
         let fc = FreightContract {
@@ -104,6 +198,12 @@ impl RoadFreight {
             total_km: 0,
             computed_cost: 0,
             last_paid: 0,
+            telemetry_hash: BytesN::from_array(&e, &[0u8; 32]),
+            telemetry_seq: 0,
+            milestones,
+            released_bps: 0,
+            released_count: 0,
+            released_amount: 0,
         };
         put(&e, &DataKey::Contract(id), &fc);
 
@@ -112,6 +212,7 @@ impl RoadFreight {
     }
 
     pub fn accept(e: Env, id: u128, carrier: Address) -> Result<(), Error> {
+        require_not_paused(&e)?;
         carrier.require_auth();
         let mut fc: FreightContract = get(&e, &DataKey::Contract(id)).ok_or(Error::NotFound)?;
         if fc.carrier != carrier { return Err(Error::Unauthorized); }
@@ -123,7 +224,8 @@ impl RoadFreight {
         Ok(())
     }
 
-    pub fn mark_funded(e: Env, id: u128, shipper: Address) -> Result<(), Error> {
+    pub fn fund_escrow(e: Env, id: u128, shipper: Address) -> Result<(), Error> {
+        require_not_paused(&e)?;
         shipper.require_auth();
         let mut fc: FreightContract = get(&e, &DataKey::Contract(id)).ok_or(Error::NotFound)?;
         if fc.shipper != shipper { return Err(Error::Unauthorized); }
@@ -131,11 +233,40 @@ impl RoadFreight {
 
         fc.escrow_funded = true;
         put(&e, &DataKey::Contract(id), &fc);
-        e.events().publish((symbol_short!("EV"), symbol_short!("FUNDED")), id);
+        e.events().publish((symbol_short!("EV"), symbol_short!("FUNDED")), (id, fc.price));
+
+        token::Client::new(&e, &fc.token).transfer(&shipper, &e.current_contract_address(), &fc.price);
+        Ok(())
+    }
+
+    pub fn transfer_carrier(e: Env, id: u128, current: Address, new_carrier: Address) -> Result<(), Error> {
+        require_not_paused(&e)?;
+        current.require_auth();
+        let mut fc: FreightContract = get(&e, &DataKey::Contract(id)).ok_or(Error::NotFound)?;
+        if current != fc.carrier { return Err(Error::Unauthorized); }
+        if !matches!(fc.status, Status::Active | Status::InTransit) { return Err(Error::BadState); }
+
+        fc.carrier = new_carrier.clone();
+        put(&e, &DataKey::Contract(id), &fc);
+        e.events().publish((symbol_short!("EV"), symbol_short!("ROT_CAR")), (id, current, new_carrier));
+        Ok(())
+    }
+
+    pub fn transfer_shipper(e: Env, id: u128, current: Address, new_shipper: Address) -> Result<(), Error> {
+        require_not_paused(&e)?;
+        current.require_auth();
+        let mut fc: FreightContract = get(&e, &DataKey::Contract(id)).ok_or(Error::NotFound)?;
+        if current != fc.shipper { return Err(Error::Unauthorized); }
+        if matches!(fc.status, Status::Delivered | Status::Settled) { return Err(Error::BadState); }
+
+        fc.shipper = new_shipper.clone();
+        put(&e, &DataKey::Contract(id), &fc);
+        e.events().publish((symbol_short!("EV"), symbol_short!("ROT_SHIP")), (id, current, new_shipper));
         Ok(())
     }
 
     pub fn start_trip(e: Env, id: u128, caller: Address) -> Result<(), Error> {
+        require_not_paused(&e)?;
         caller.require_auth();
         let mut fc: FreightContract = get(&e, &DataKey::Contract(id)).ok_or(Error::NotFound)?;
         if !(caller == fc.shipper || caller == fc.carrier) { return Err(Error::Unauthorized); }
@@ -156,6 +287,7 @@ impl RoadFreight {
         add_cost: i128,
         oracle: Address,
     ) -> Result<(), Error> {
+        require_not_paused(&e)?;
         oracle.require_auth();
         let mut fc: FreightContract = get(&e, &DataKey::Contract(id)).ok_or(Error::NotFound)?;
         if !matches!(fc.status, Status::InTransit) { return Err(Error::BadState); }
@@ -163,13 +295,35 @@ impl RoadFreight {
         fc.total_secs = fc.total_secs.saturating_add(add_secs as u64);
         fc.total_km   = fc.total_km.saturating_add(add_km);
         fc.computed_cost = fc.computed_cost.saturating_add(add_cost);
+
+        let seq = fc.telemetry_seq;
+        fc.telemetry_hash = chain_telemetry(&e, &fc.telemetry_hash, seq, add_secs, add_km, add_cost);
+        fc.telemetry_seq = seq + 1;
         put(&e, &DataKey::Contract(id), &fc);
 
-        e.events().publish((symbol_short!("EV"), symbol_short!("TEL")), (id, add_secs, add_km));
+        e.events().publish(
+            (symbol_short!("EV"), symbol_short!("TEL")),
+            (id, seq, add_secs, add_km, fc.telemetry_hash.clone()),
+        );
         Ok(())
     }
 
+    pub fn verify_telemetry(e: Env, id: u128, entries: Vec<(u32, u32, i128)>) -> Result<bool, Error> {
+        let fc: FreightContract = get_contract_either(&e, id).ok_or(Error::NotFound)?;
+
+        let mut hash = BytesN::from_array(&e, &[0u8; 32]);
+        let mut seq: u64 = 0;
+        for entry in entries.iter() {
+            let (add_secs, add_km, add_cost) = entry;
+            hash = chain_telemetry(&e, &hash, seq, add_secs, add_km, add_cost);
+            seq += 1;
+        }
+
+        Ok(hash == fc.telemetry_hash && seq == fc.telemetry_seq)
+    }
+
     pub fn submit_pod(e: Env, id: u128, pod_hash: BytesN<32>, caller: Address) -> Result<(), Error> {
+        require_not_paused(&e)?;
         caller.require_auth();
         let mut fc: FreightContract = get(&e, &DataKey::Contract(id)).ok_or(Error::NotFound)?;
         if !(caller == fc.shipper || caller == fc.carrier) { return Err(Error::Unauthorized); }
@@ -182,24 +336,410 @@ impl RoadFreight {
         Ok(())
     }
 
+    pub fn release_milestone(e: Env, id: u128, milestone_index: u32, invoker: Address) -> Result<i128, Error> {
+        require_not_paused(&e)?;
+        invoker.require_auth();
+        let mut fc: FreightContract = get_contract_either(&e, id).ok_or(Error::NotFound)?;
+        if !(invoker == fc.shipper || invoker == fc.carrier) { return Err(Error::Unauthorized); }
+        if !fc.escrow_funded { return Err(Error::EscrowNotFunded); }
+        if matches!(fc.status, Status::Settled) { return Err(Error::AlreadySettled); }
+        if milestone_index != fc.released_count { return Err(Error::BadState); }
+
+        let (threshold_km, bps) = fc.milestones.get(milestone_index).ok_or(Error::NotFound)?;
+        if fc.total_km < threshold_km { return Err(Error::BadState); }
+
+        let amount = fc.price * (bps as i128) / 10_000;
+
+        fc.released_bps += bps as u32;
+        fc.released_count += 1;
+        fc.released_amount += amount;
+        put(&e, &DataKey::Contract(id), &fc);
+        e.events().publish((symbol_short!("EV"), symbol_short!("MILESTONE")), (id, milestone_index, amount));
+
+        if amount > 0 {
+            token::Client::new(&e, &fc.token).transfer(&e.current_contract_address(), &fc.carrier, &amount);
+        }
+        Ok(amount)
+    }
+
     pub fn evaluate_and_settle(e: Env, id: u128, invoker: Address) -> Result<i128, Error> {
+        require_not_paused(&e)?;
         invoker.require_auth();
         let mut fc: FreightContract = get(&e, &DataKey::Contract(id)).ok_or(Error::NotFound)?;
         if !matches!(fc.status, Status::Delivered) { return Err(Error::BadState); }
         if !fc.escrow_funded { return Err(Error::EscrowNotFunded); }
 
         let now = e.ledger().timestamp();
-        let pay = if now <= fc.deadline_unix { fc.price } else { fc.price / 2 };
+        let remaining_price = fc.price - fc.released_amount;
+        let pay = if now <= fc.deadline_unix { remaining_price } else { remaining_price / 2 };
+        let refund = remaining_price - pay;
 
         fc.status = Status::Settled;
         fc.last_paid = pay;
-        put(&e, &DataKey::Contract(id), &fc);
+        archive_contract(&e, id, &fc);
+        e.events().publish((symbol_short!("EV"), symbol_short!("SETTLED")), (id, pay, refund));
+
+        let token_client = token::Client::new(&e, &fc.token);
+        let contract_address = e.current_contract_address();
+        if pay > 0 {
+            token_client.transfer(&contract_address, &fc.carrier, &pay);
+        }
+        if refund > 0 {
+            token_client.transfer(&contract_address, &fc.shipper, &refund);
+        }
 
-        e.events().publish((symbol_short!("EV"), symbol_short!("SETTLED")), (id, pay));
         Ok(pay)
     }
 
+    pub fn bump_ttl(e: Env, id: u128) -> Result<(), Error> {
+        let key = DataKey::Archived(id);
+        if !e.storage().persistent().has(&key) { return Err(Error::NotFound); }
+
+        e.storage().persistent().extend_ttl(&key, ARCHIVE_TTL_THRESHOLD, ARCHIVE_TTL_EXTEND);
+        Ok(())
+    }
+
     pub fn get_contract(e: Env, id: u128) -> Result<FreightContract, Error> {
-        get(&e, &DataKey::Contract(id)).ok_or(Error::NotFound)
+        get_contract_either(&e, id).ok_or(Error::NotFound)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+    fn create_token<'a>(e: &Env, admin: &Address) -> (Address, token::StellarAssetClient<'a>, token::Client<'a>) {
+        let sac = e.register_stellar_asset_contract(admin.clone());
+        (
+            sac.clone(),
+            token::StellarAssetClient::new(e, &sac),
+            token::Client::new(e, &sac),
+        )
+    }
+
+    fn create_freight_client(e: &Env) -> RoadFreightClient {
+        RoadFreightClient::new(e, &e.register_contract(None, RoadFreight))
+    }
+
+    #[test]
+    fn test_fund_escrow_and_settle_move_real_tokens() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let shipper = Address::generate(&e);
+        let carrier = Address::generate(&e);
+        let token_admin = Address::generate(&e);
+        let (token_id, token_sac, token_client) = create_token(&e, &token_admin);
+        token_sac.mint(&shipper, &1_000);
+
+        let client = create_freight_client(&e);
+        let deadline = e.ledger().timestamp() + 1_000;
+        let id = client.create_contract(
+            &shipper,
+            &carrier,
+            &String::from_str(&e, "A"),
+            &String::from_str(&e, "B"),
+            &token_id,
+            &1_000,
+            &deadline,
+            &BytesN::from_array(&e, &[0u8; 32]),
+            &Vec::new(&e),
+        );
+
+        client.accept(&id, &carrier);
+        client.fund_escrow(&id, &shipper);
+        assert_eq!(token_client.balance(&shipper), 0);
+        assert_eq!(token_client.balance(&client.address), 1_000);
+
+        client.start_trip(&id, &shipper);
+        client.submit_pod(&id, &BytesN::from_array(&e, &[1u8; 32]), &shipper);
+        let pay = client.evaluate_and_settle(&id, &shipper);
+
+        assert_eq!(pay, 1_000);
+        assert_eq!(token_client.balance(&carrier), 1_000);
+        assert_eq!(token_client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_milestone_release_and_final_settle_split() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let shipper = Address::generate(&e);
+        let carrier = Address::generate(&e);
+        let token_admin = Address::generate(&e);
+        let (token_id, token_sac, token_client) = create_token(&e, &token_admin);
+        token_sac.mint(&shipper, &1_000);
+
+        let client = create_freight_client(&e);
+        let deadline = e.ledger().timestamp() + 1_000;
+        let mut milestones = Vec::new(&e);
+        milestones.push_back((50u32, 4_000u16));
+        let id = client.create_contract(
+            &shipper,
+            &carrier,
+            &String::from_str(&e, "A"),
+            &String::from_str(&e, "B"),
+            &token_id,
+            &1_000,
+            &deadline,
+            &BytesN::from_array(&e, &[0u8; 32]),
+            &milestones,
+        );
+
+        client.accept(&id, &carrier);
+        client.fund_escrow(&id, &shipper);
+        client.start_trip(&id, &shipper);
+        client.log_telemetry(&id, &100u32, &50u32, &0i128, &shipper);
+
+        let milestone_pay = client.release_milestone(&id, &0u32, &carrier);
+        assert_eq!(milestone_pay, 400);
+        assert_eq!(token_client.balance(&carrier), 400);
+
+        client.submit_pod(&id, &BytesN::from_array(&e, &[1u8; 32]), &shipper);
+        let final_pay = client.evaluate_and_settle(&id, &shipper);
+
+        assert_eq!(final_pay, 600);
+        assert_eq!(token_client.balance(&carrier), 1_000);
+        assert_eq!(token_client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_milestone_truncation_dust_is_not_stranded_at_settlement() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let shipper = Address::generate(&e);
+        let carrier = Address::generate(&e);
+        let token_admin = Address::generate(&e);
+        let (token_id, token_sac, token_client) = create_token(&e, &token_admin);
+        token_sac.mint(&shipper, &10_001);
+
+        let client = create_freight_client(&e);
+        let deadline = e.ledger().timestamp() + 1_000;
+        let mut milestones = Vec::new(&e);
+        milestones.push_back((50u32, 5_000u16));
+        let id = client.create_contract(
+            &shipper,
+            &carrier,
+            &String::from_str(&e, "A"),
+            &String::from_str(&e, "B"),
+            &token_id,
+            &10_001,
+            &deadline,
+            &BytesN::from_array(&e, &[0u8; 32]),
+            &milestones,
+        );
+
+        client.accept(&id, &carrier);
+        client.fund_escrow(&id, &shipper);
+        client.start_trip(&id, &shipper);
+        client.log_telemetry(&id, &100u32, &50u32, &0i128, &shipper);
+
+        // 10_001 * 5_000 / 10_000 truncates to 5_000, leaving 1 unit of dust.
+        let milestone_pay = client.release_milestone(&id, &0u32, &carrier);
+        assert_eq!(milestone_pay, 5_000);
+
+        client.submit_pod(&id, &BytesN::from_array(&e, &[1u8; 32]), &shipper);
+        let final_pay = client.evaluate_and_settle(&id, &shipper);
+
+        // The dust unit is folded into the final settlement rather than stranded.
+        assert_eq!(final_pay, 5_001);
+        assert_eq!(token_client.balance(&carrier), 10_001);
+        assert_eq!(token_client.balance(&client.address), 0);
+    }
+
+    #[test]
+    fn test_telemetry_hashchain_round_trip() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let shipper = Address::generate(&e);
+        let carrier = Address::generate(&e);
+        let token_admin = Address::generate(&e);
+        let (token_id, token_sac, _token_client) = create_token(&e, &token_admin);
+        token_sac.mint(&shipper, &1_000);
+
+        let client = create_freight_client(&e);
+        let deadline = e.ledger().timestamp() + 1_000;
+        let id = client.create_contract(
+            &shipper,
+            &carrier,
+            &String::from_str(&e, "A"),
+            &String::from_str(&e, "B"),
+            &token_id,
+            &1_000,
+            &deadline,
+            &BytesN::from_array(&e, &[0u8; 32]),
+            &Vec::new(&e),
+        );
+
+        client.accept(&id, &carrier);
+        client.fund_escrow(&id, &shipper);
+        client.start_trip(&id, &shipper);
+        client.log_telemetry(&id, &100u32, &10u32, &5i128, &shipper);
+        client.log_telemetry(&id, &200u32, &20u32, &7i128, &shipper);
+
+        let mut entries = Vec::new(&e);
+        entries.push_back((100u32, 10u32, 5i128));
+        entries.push_back((200u32, 20u32, 7i128));
+        assert!(client.verify_telemetry(&id, &entries));
+
+        let mut reordered = Vec::new(&e);
+        reordered.push_back((200u32, 20u32, 7i128));
+        reordered.push_back((100u32, 10u32, 5i128));
+        assert!(!client.verify_telemetry(&id, &reordered));
+
+        // A shipper must still be able to prove the telemetry sequence after settlement.
+        client.submit_pod(&id, &BytesN::from_array(&e, &[1u8; 32]), &shipper);
+        client.evaluate_and_settle(&id, &shipper);
+        assert!(client.verify_telemetry(&id, &entries));
+    }
+
+    #[test]
+    fn test_get_contract_and_bump_ttl_read_the_archived_record() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let shipper = Address::generate(&e);
+        let carrier = Address::generate(&e);
+        let token_admin = Address::generate(&e);
+        let (token_id, token_sac, _token_client) = create_token(&e, &token_admin);
+        token_sac.mint(&shipper, &1_000);
+
+        let client = create_freight_client(&e);
+        let deadline = e.ledger().timestamp() + 1_000;
+        let id = client.create_contract(
+            &shipper,
+            &carrier,
+            &String::from_str(&e, "A"),
+            &String::from_str(&e, "B"),
+            &token_id,
+            &1_000,
+            &deadline,
+            &BytesN::from_array(&e, &[0u8; 32]),
+            &Vec::new(&e),
+        );
+        client.accept(&id, &carrier);
+        client.fund_escrow(&id, &shipper);
+        client.start_trip(&id, &shipper);
+        client.submit_pod(&id, &BytesN::from_array(&e, &[1u8; 32]), &shipper);
+        client.evaluate_and_settle(&id, &shipper);
+
+        let archived = client.get_contract(&id);
+        assert!(matches!(archived.status, Status::Settled));
+        assert_eq!(archived.last_paid, 1_000);
+
+        client.bump_ttl(&id);
+        assert_eq!(client.try_bump_ttl(&(id + 1)), Err(Ok(Error::NotFound)));
+    }
+
+    #[test]
+    fn test_pause_blocks_mutation_then_resume_allows_it() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let shipper = Address::generate(&e);
+        let carrier = Address::generate(&e);
+        let admin = Address::generate(&e);
+        let other = Address::generate(&e);
+        let token_admin = Address::generate(&e);
+        let (token_id, token_sac, _token_client) = create_token(&e, &token_admin);
+        token_sac.mint(&shipper, &1_000);
+
+        let client = create_freight_client(&e);
+        client.initialize(&admin);
+
+        assert_eq!(client.try_pause(&other), Err(Ok(Error::Unauthorized)));
+
+        let deadline = e.ledger().timestamp() + 1_000;
+        let id = client.create_contract(
+            &shipper,
+            &carrier,
+            &String::from_str(&e, "A"),
+            &String::from_str(&e, "B"),
+            &token_id,
+            &1_000,
+            &deadline,
+            &BytesN::from_array(&e, &[0u8; 32]),
+            &Vec::new(&e),
+        );
+        client.accept(&id, &carrier);
+
+        client.pause(&admin);
+        assert_eq!(client.try_fund_escrow(&id, &shipper), Err(Ok(Error::Paused)));
+
+        client.resume(&admin);
+        client.fund_escrow(&id, &shipper);
+        assert!(client.get_contract(&id).escrow_funded);
+    }
+
+    #[test]
+    fn test_pause_requires_initialization() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let admin = Address::generate(&e);
+        let client = create_freight_client(&e);
+
+        assert_eq!(client.try_pause(&admin), Err(Ok(Error::NotInitialized)));
+    }
+
+    #[test]
+    fn test_rotate_carrier_and_shipper() {
+        let e = Env::default();
+        e.mock_all_auths();
+
+        let shipper = Address::generate(&e);
+        let carrier = Address::generate(&e);
+        let new_carrier = Address::generate(&e);
+        let new_shipper = Address::generate(&e);
+        let token_admin = Address::generate(&e);
+        let (token_id, token_sac, token_client) = create_token(&e, &token_admin);
+        token_sac.mint(&shipper, &1_000);
+
+        let client = create_freight_client(&e);
+        let deadline = e.ledger().timestamp() + 1_000;
+        let id = client.create_contract(
+            &shipper,
+            &carrier,
+            &String::from_str(&e, "A"),
+            &String::from_str(&e, "B"),
+            &token_id,
+            &1_000,
+            &deadline,
+            &BytesN::from_array(&e, &[0u8; 32]),
+            &Vec::new(&e),
+        );
+        client.accept(&id, &carrier);
+        client.fund_escrow(&id, &shipper);
+
+        client.transfer_carrier(&id, &carrier, &new_carrier);
+        assert_eq!(client.get_contract(&id).carrier, new_carrier);
+
+        client.transfer_shipper(&id, &shipper, &new_shipper);
+        assert_eq!(client.get_contract(&id).shipper, new_shipper);
+
+        // The old carrier no longer holds the role; subsequent auth flows to the new holder.
+        assert_eq!(client.try_transfer_carrier(&id, &carrier, &new_carrier), Err(Ok(Error::Unauthorized)));
+
+        client.start_trip(&id, &new_shipper);
+        client.submit_pod(&id, &BytesN::from_array(&e, &[1u8; 32]), &new_shipper);
+
+        // Carrier rotation is rejected once the shipment is no longer Active/InTransit.
+        assert_eq!(
+            client.try_transfer_carrier(&id, &new_carrier, &carrier),
+            Err(Ok(Error::BadState)),
+        );
+        // Shipper rotation is rejected once Delivered.
+        assert_eq!(
+            client.try_transfer_shipper(&id, &new_shipper, &shipper),
+            Err(Ok(Error::BadState)),
+        );
+
+        let pay = client.evaluate_and_settle(&id, &new_shipper);
+        assert_eq!(pay, 1_000);
+        assert_eq!(token_client.balance(&new_carrier), 1_000);
     }
 }